@@ -0,0 +1,9 @@
+//! Base elements required to build views.
+//!
+//! This module defines the [`View`] trait, used to represent any view on the
+//! screen, along with helpers to wrap and combine views.
+
+#[macro_use]
+mod view_wrapper;
+
+pub use self::view_wrapper::{MapView, ViewWrapper};
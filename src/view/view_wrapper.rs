@@ -19,7 +19,42 @@ use std::any::Any;
 /// behaviors (the default implementations simply forwards the calls to the
 /// child view).
 ///
+/// There is exactly one `wrap_*` method per [`View`] method, and the blanket
+/// `impl<T: ViewWrapper> View for T` routes every `View` method through its
+/// `wrap_*` counterpart.  The current `View` surface is fully covered:
+///
+/// | `View` method     | `wrap_*` method        |
+/// |-------------------|------------------------|
+/// | `draw`            | [`wrap_draw`]          |
+/// | `layout`          | [`wrap_layout`]        |
+/// | `needs_relayout`  | [`wrap_needs_relayout`]|
+/// | `required_size`   | [`wrap_required_size`] |
+/// | `on_event`        | [`wrap_on_event`]      |
+/// | `call_on_any`     | [`wrap_call_on_any`]   |
+/// | `focus_view`      | [`wrap_focus_view`]    |
+/// | `take_focus`      | [`wrap_take_focus`]    |
+/// | `important_area`  | [`wrap_important_area`]|
+///
+/// Keep this correspondence complete: a `View` method with no matching
+/// `wrap_*` would fall back to the default `View` implementation, silently
+/// dropping the forward to the child view.  When a new method is added to
+/// `View`, add the matching `wrap_*` here and wire it in the blanket impl
+/// below.  (The [`wrap_impl!`] macro only generates `type V`,
+/// `with_view`/`with_view_mut` and `into_inner`, so it needs no change when a
+/// `wrap_*` method is added.)
+///
+/// [`wrap_draw`]: #method.wrap_draw
+/// [`wrap_layout`]: #method.wrap_layout
+/// [`wrap_needs_relayout`]: #method.wrap_needs_relayout
+/// [`wrap_required_size`]: #method.wrap_required_size
+/// [`wrap_on_event`]: #method.wrap_on_event
+/// [`wrap_call_on_any`]: #method.wrap_call_on_any
+/// [`wrap_focus_view`]: #method.wrap_focus_view
+/// [`wrap_take_focus`]: #method.wrap_take_focus
+/// [`wrap_important_area`]: #method.wrap_important_area
+///
 /// [`wrap_impl!`]: ../macro.wrap_impl.html
+/// [`View`]: trait.View.html
 pub trait ViewWrapper: 'static {
     /// Type that this view wraps.
     type V: View + ?Sized;
@@ -49,32 +84,62 @@ pub trait ViewWrapper: 'static {
         Err(self)
     }
 
+    /// Called when the inner view was unexpectedly unavailable.
+    ///
+    /// Each `wrap_*` method calls this with the name of the `View` method it
+    /// wraps (for example `"draw"` from `wrap_draw`) when
+    /// `with_view`/`with_view_mut` returns `None`, just before falling back to
+    /// the default value.  The default implementation is a no-op, so release
+    /// behavior is unchanged; override it to log or panic in debug builds when
+    /// a wrapper around a `RefCell`/shared view hits an unexpected borrow
+    /// conflict.
+    fn on_inner_unavailable(&self, _method: &'static str) {}
+
     /// Wraps the `draw` method.
     fn wrap_draw(&self, printer: &Printer<'_, '_>) {
-        self.with_view(|v| v.draw(printer));
+        if self.with_view(|v| v.draw(printer)).is_none() {
+            self.on_inner_unavailable("draw");
+        }
     }
 
     /// Wraps the `required_size` method.
     fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
-        self.with_view_mut(|v| v.required_size(req))
-            .unwrap_or_else(Vec2::zero)
+        match self.with_view_mut(|v| v.required_size(req)) {
+            Some(size) => size,
+            None => {
+                self.on_inner_unavailable("required_size");
+                Vec2::zero()
+            }
+        }
     }
 
     /// Wraps the `on_event` method.
     fn wrap_on_event(&mut self, ch: Event) -> EventResult {
-        self.with_view_mut(|v| v.on_event(ch))
-            .unwrap_or(EventResult::Ignored)
+        match self.with_view_mut(|v| v.on_event(ch)) {
+            Some(result) => result,
+            None => {
+                self.on_inner_unavailable("on_event");
+                EventResult::Ignored
+            }
+        }
     }
 
     /// Wraps the `layout` method.
     fn wrap_layout(&mut self, size: Vec2) {
-        self.with_view_mut(|v| v.layout(size));
+        if self.with_view_mut(|v| v.layout(size)).is_none() {
+            self.on_inner_unavailable("layout");
+        }
     }
 
     /// Wraps the `take_focus` method.
     fn wrap_take_focus(&mut self, source: Direction) -> bool {
-        self.with_view_mut(|v| v.take_focus(source))
-            .unwrap_or(false)
+        match self.with_view_mut(|v| v.take_focus(source)) {
+            Some(result) => result,
+            None => {
+                self.on_inner_unavailable("take_focus");
+                false
+            }
+        }
     }
 
     /// Wraps the `find` method.
@@ -83,28 +148,151 @@ pub trait ViewWrapper: 'static {
         selector: &Selector<'_>,
         callback: AnyCb<'a>,
     ) {
-        self.with_view_mut(|v| v.call_on_any(selector, callback));
+        if self
+            .with_view_mut(|v| v.call_on_any(selector, callback))
+            .is_none()
+        {
+            self.on_inner_unavailable("call_on_any");
+        }
     }
 
     /// Wraps the `focus_view` method.
     fn wrap_focus_view(&mut self, selector: &Selector<'_>) -> Result<(), ()> {
-        self.with_view_mut(|v| v.focus_view(selector))
-            .unwrap_or(Err(()))
+        match self.with_view_mut(|v| v.focus_view(selector)) {
+            Some(result) => result,
+            None => {
+                self.on_inner_unavailable("focus_view");
+                Err(())
+            }
+        }
     }
 
     /// Wraps the `needs_relayout` method.
     fn wrap_needs_relayout(&self) -> bool {
-        self.with_view(View::needs_relayout).unwrap_or(true)
+        match self.with_view(View::needs_relayout) {
+            Some(result) => result,
+            None => {
+                self.on_inner_unavailable("needs_relayout");
+                true
+            }
+        }
     }
 
     /// Wraps the `important_area` method.
     fn wrap_important_area(&self, size: Vec2) -> Rect {
-        self.with_view(|v| v.important_area(size))
-            .unwrap_or_else(|| Rect::from((0, 0)))
+        match self.with_view(|v| v.important_area(size)) {
+            Some(area) => area,
+            None => {
+                self.on_inner_unavailable("important_area");
+                Rect::from((0, 0))
+            }
+        }
+    }
+}
+
+/// Closure used by [`MapView`] to override `draw`.
+type DrawFn<T> = dyn Fn(&T, &Printer<'_, '_>);
+/// Closure used by [`MapView`] to override `on_event`.
+type OnEventFn<T> = dyn FnMut(&mut T, Event) -> EventResult;
+/// Closure used by [`MapView`] to override `required_size`.
+type RequiredSizeFn<T> = dyn FnMut(&mut T, Vec2) -> Vec2;
+
+/// Wraps a view and overrides individual methods with closures.
+///
+/// This is a lightweight alternative to defining a fresh struct and
+/// `impl ViewWrapper` (with [`wrap_impl!`]) whenever you only need to tweak
+/// one or two methods of an existing view.  Each `on_*`/`map_*` builder stores
+/// a boxed closure; the matching `wrap_*` method dispatches to it, falling back
+/// to the default forwarding when no closure is set.
+///
+/// [`wrap_impl!`]: ../macro.wrap_impl.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use cursive::view::{MapView, View};
+/// # use cursive::views::DummyView;
+/// let _wrapped = MapView::new(DummyView)
+///     .map_required_size(|_v, req| req);
+/// ```
+pub struct MapView<T: View> {
+    view: T,
+    on_draw: Option<Box<DrawFn<T>>>,
+    on_event: Option<Box<OnEventFn<T>>>,
+    map_required_size: Option<Box<RequiredSizeFn<T>>>,
+}
+
+impl<T: View> MapView<T> {
+    /// Wraps the given view, without overriding any method yet.
+    pub fn new(view: T) -> Self {
+        MapView {
+            view,
+            on_draw: None,
+            on_event: None,
+            map_required_size: None,
+        }
+    }
+
+    crate::inner_getters!(self.view: T);
+
+    /// Overrides the `draw` method with the given closure.
+    pub fn on_draw<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&T, &Printer<'_, '_>) + 'static,
+    {
+        self.on_draw = Some(Box::new(f));
+        self
+    }
+
+    /// Overrides the `on_event` method with the given closure.
+    pub fn on_event<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&mut T, Event) -> EventResult + 'static,
+    {
+        self.on_event = Some(Box::new(f));
+        self
+    }
+
+    /// Overrides the `required_size` method with the given closure.
+    pub fn map_required_size<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&mut T, Vec2) -> Vec2 + 'static,
+    {
+        self.map_required_size = Some(Box::new(f));
+        self
+    }
+}
+
+impl<T: View> ViewWrapper for MapView<T> {
+    crate::wrap_impl!(self.view: T);
+
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        match &self.on_draw {
+            Some(f) => f(&self.view, printer),
+            None => self.view.draw(printer),
+        }
+    }
+
+    fn wrap_on_event(&mut self, ch: Event) -> EventResult {
+        match &mut self.on_event {
+            Some(f) => f(&mut self.view, ch),
+            None => self.view.on_event(ch),
+        }
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        match &mut self.map_required_size {
+            Some(f) => f(&mut self.view, req),
+            None => self.view.required_size(req),
+        }
     }
 }
 
 // The main point of implementing ViewWrapper is to have View for free.
+//
+// Every `View` method is forwarded through its `wrap_*` counterpart; if one is
+// missing here the default `View` implementation takes over and the wrapper
+// stops forwarding to its child for that method.
 impl<T: ViewWrapper> View for T {
     fn draw(&self, printer: &Printer<'_, '_>) {
         self.wrap_draw(printer);
@@ -190,6 +378,64 @@ macro_rules! wrap_impl {
     };
 }
 
+/// Variant of [`wrap_impl!`] for a view held behind a shared `Rc<RefCell<_>>`.
+///
+/// It defines `with_view`, `with_view_mut` and `into_inner` for a field of type
+/// `Rc<RefCell<T>>`, using `try_borrow`/`try_borrow_mut` so that the `None` path
+/// of `with_view`/`with_view_mut` is actually exercised when the view is already
+/// borrowed elsewhere.  `into_inner` returns `Err(self)` while the `Rc` is still
+/// shared, and otherwise unwraps the inner view.
+///
+/// [`wrap_impl!`]: ../macro.wrap_impl.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::cell::RefCell;
+/// # use std::rc::Rc;
+/// # use cursive::view::{View, ViewWrapper};
+/// struct SharedView<T: View> {
+///     view: Rc<RefCell<T>>,
+/// }
+///
+/// impl<T: View> ViewWrapper for SharedView<T> {
+///     cursive::wrap_impl_shared!(self.view: T);
+/// }
+/// # fn main() { }
+/// ```
+#[macro_export]
+macro_rules! wrap_impl_shared {
+    (self.$v:ident: $t:ty) => {
+        type V = $t;
+
+        fn with_view<F, R>(&self, f: F) -> Option<R>
+            where F: FnOnce(&Self::V) -> R
+        {
+            self.$v.try_borrow().ok().map(|v| f(&*v))
+        }
+
+        fn with_view_mut<F, R>(&mut self, f: F) -> Option<R>
+            where F: FnOnce(&mut Self::V) -> R
+        {
+            self.$v.try_borrow_mut().ok().map(|mut v| f(&mut *v))
+        }
+
+        fn into_inner(self) -> Result<Self::V, Self> where Self::V: Sized {
+            // Succeed whenever we are the sole strong owner (outstanding weak
+            // handles don't block `try_unwrap`); otherwise the view is still
+            // shared and we hand `self` back untouched.
+            if ::std::rc::Rc::strong_count(&self.$v) > 1 {
+                Err(self)
+            } else {
+                // Sole strong owner, so `try_unwrap` cannot fail.
+                Ok(::std::rc::Rc::try_unwrap(self.$v)
+                    .unwrap_or_else(|_| unreachable!())
+                    .into_inner())
+            }
+        }
+    };
+}
+
 /// Convenient macro to implement the getters for inner [`View`] in
 /// [`ViewWrapper`].
 ///